@@ -1,12 +1,22 @@
+use core::convert::Infallible;
 use core::marker::PhantomData;
 
+// `StatefulOutputPin` and the `toggleable` marker only exist under the
+// fallible v2 digital traits, so every digital impl in this module uses v2
+// and reports `Infallible` rather than mixing it with the deprecated,
+// infallible top-level `digital` re-export.
+use embedded_hal::digital::v2::{toggleable, InputPin, OutputPin, StatefulOutputPin};
+
+use crate::pmc::Pmc;
+
 /// Extension trait to split a GPIO peripheral in independent pins and registers
 pub trait GpioExt {
     /// The type to split the GPIO into.
     type Parts;
 
-    /// Splits the GPIO block into independent pins and registers.
-    fn split(self) -> Self::Parts;
+    /// Splits the GPIO block into independent pins and registers, enabling
+    /// its peripheral clock in the PMC along the way.
+    fn split(self, pmc: &mut Pmc) -> Self::Parts;
 }
 
 pub struct Input<MODE> {
@@ -24,6 +34,71 @@ pub struct Output<MODE> {
     _mode: PhantomData<MODE>,
 }
 
+/// Push-pull output (type state)
+pub struct PushPull;
+/// Open-drain output (type state)
+pub struct OpenDrain;
+
+/// Edge/level detection mode for a PIO interrupt source.
+///
+/// `AnyEdge` is the PIO controller's default: an interrupt fires on either a
+/// rising or a falling edge. The other variants enable the "additional
+/// interrupt modes" (`AIMER`) to narrow detection to a single edge or level.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Interrupt {
+    /// Interrupt on either a rising or a falling edge (the PIO default)
+    AnyEdge,
+    /// Interrupt only on a rising edge
+    RisingEdge,
+    /// Interrupt only on a falling edge
+    FallingEdge,
+    /// Interrupt while the pin reads high
+    HighLevel,
+    /// Interrupt while the pin reads low
+    LowLevel,
+}
+
+impl Default for Interrupt {
+    fn default() -> Self {
+        Interrupt::AnyEdge
+    }
+}
+
+/// A pin whose direction and pull resistors are chosen at runtime rather
+/// than encoded in its type (type state).
+///
+/// Produced by [`Pin::into_dynamic`]. Unlike the other type states, accessors
+/// on a `Dynamic` pin are fallible: [`Pin::set_high`], for example, returns
+/// [`PinModeError::NotOutput`] if the pin is currently configured as an input.
+pub struct Dynamic {
+    mode: DynamicMode,
+}
+
+/// The runtime-selected configuration of a [`Dynamic`] pin
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DynamicMode {
+    /// Floating input
+    InputFloating,
+    /// Pulled-up input
+    InputPullUp,
+    /// Pulled-down input
+    InputPullDown,
+    /// Push-pull output
+    OutputPushPull,
+    /// Open-drain (multi-driver) output
+    OutputOpenDrain,
+}
+
+/// Error returned when a [`Dynamic`] pin is accessed in a way its current
+/// [`DynamicMode`] does not support
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PinModeError {
+    /// The pin is not currently configured as an output
+    NotOutput,
+    /// The pin is not currently configured as an input
+    NotInput,
+}
+
 /// Pin assigned to peripheral A (type state)
 pub struct PeripheralA;
 /// Pin assigned to peripheral B (type state)
@@ -33,261 +108,901 @@ pub struct PeripheralC;
 /// Pin assigned to peripheral D (type state)
 pub struct PeripheralD;
 
-macro_rules! pio {
-    ($PIOX:ident, $piox:ident, $pioy:ident, $PXx:ident, [
-        $($PXi:ident: ($pxi:ident, $i:expr, $MODE:ty),)+
-    ]) => {
-        /// PIO
-        pub mod $piox {
-            use core::marker::PhantomData;
+/// The register layout is identical for PIOA, PIOB and PIOC; only the base
+/// address differs, so every port is accessed through this one type.
+///
+/// The PAC still generates a distinct nominal `RegisterBlock` per port, so
+/// the assertions below catch it at compile time if that ever stops holding,
+/// rather than silently reinterpreting a PIOB/PIOC pointer as `pioa`'s. Equal
+/// size wouldn't rule out reordered or differently-padded fields, so this
+/// checks the offset of every register this module actually dereferences
+/// through the reinterpreted pointer.
+type RegisterBlock = atsam4s16b::pioa::RegisterBlock;
 
-            use embedded_hal::digital::OutputPin;
-            pub use atsam4s16b::{$pioy, $PIOX};
+macro_rules! assert_same_offset {
+    ($($field:ident),+ $(,)?) => {
+        $(
+            const _: () = assert!(
+                core::mem::offset_of!(RegisterBlock, $field)
+                    == core::mem::offset_of!(atsam4s16b::piob::RegisterBlock, $field)
+            );
+            const _: () = assert!(
+                core::mem::offset_of!(RegisterBlock, $field)
+                    == core::mem::offset_of!(atsam4s16b::pioc::RegisterBlock, $field)
+            );
+        )+
+    };
+}
 
-            use super::{
-                Input, Floating, PullUp, PullDown, GpioExt,
-                Output, PeripheralA, PeripheralB, PeripheralC, PeripheralD
-            };
+assert_same_offset!(
+    per, pdr, oer, odr, puer, pudr, ppder, ppddr, ifer, ifdr, mder, mddr, ier, idr, aimer, aimdr,
+    esr, lsr, rehlsr, fellsr, isr, abcdsr, sodr, codr, pdsr, odsr,
+);
 
-            pub struct Parts {
-                /// Opaque ABCDSR1 register
-                pub abcdsr1: ABCDSR1,
-                /// Opaque ABCDSR2 register
-                pub abcdsr2: ABCDSR2,
-                /// Opaque PER register
-                pub per: PER,
-                /// Opaque PDR register
-                pub pdr: PDR,
-                /// Opaque OER register
-                pub oer: OER,
-                /// Opaque ODR register
-                pub odr: ODR,
+/// Resolves `PORT` ('A', 'B' or 'C') to the base address of its PIO controller.
+fn pio_ptr(port: char) -> *const RegisterBlock {
+    match port {
+        'A' => atsam4s16b::PIOA::ptr(),
+        'B' => atsam4s16b::PIOB::ptr() as *const RegisterBlock,
+        'C' => atsam4s16b::PIOC::ptr() as *const RegisterBlock,
+        _ => unreachable!("unsupported PIO port {}", port),
+    }
+}
 
-                $(
-                    /// Pin
-                    pub $pxi: $PXi<$MODE>,
-                )+
-            }
+/// Gives uniform `(port, pin)` access to both the const-generic [`Pin`] and
+/// the type-erased [`AnyPin`], so the register-level trait impls below have
+/// the same body for both, and other modules can accept either.
+pub trait PinIndex {
+    /// The pin's type state (`Input<_>`, `Output<_>`, ...)
+    type Mode;
 
-            impl GpioExt for $PIOX {
-                type Parts = Parts;
+    /// Which PIO port ('A', 'B' or 'C') this pin belongs to
+    fn port(&self) -> char;
+    /// The pin number (0..=31) within its port
+    fn pin(&self) -> u8;
+}
 
-                fn split(self) -> Parts {
-                    Parts {
-                        abcdsr1: ABCDSR1 { _0: () },
-                        abcdsr2: ABCDSR2 { _0: () },
-                        per: PER { _0: () },
-                        pdr: PDR { _0: () },
-                        oer: OER { _0: () },
-                        odr: ODR { _0: () },
-                        $(
-                            $pxi: $PXi { _mode: PhantomData },
-                        )+
-                    }
-                }
-            }
+/// A single GPIO pin, identified at compile time by its port and pin number.
+///
+/// The concrete per-port, per-pin aliases (`PA0`, `PB3`, ...) produced by the
+/// [`pio!`] macro are just names for `Pin<PORT, N, MODE>` with `MODE` fixed
+/// to the pin's reset mode, `Input<Floating>`; there is one
+/// implementation shared by all 96 pins instead of a distinct type per pin.
+pub struct Pin<const PORT: char, const N: u8, MODE> {
+    mode: MODE,
+}
 
-            pub struct ABCDSR1 {
-                _0: (),
-            }
+/// A type-erased pin, carrying its port and pin number at runtime.
+///
+/// Produced by [`Pin::downgrade`]/[`Pin::degrade`]. Useful for storing
+/// heterogeneous pins (e.g. an LED matrix or bit-banged bus) in one array,
+/// such as `[AnyPin<Output<PushPull>>; N]`.
+pub struct AnyPin<MODE> {
+    port: char,
+    pin: u8,
+    mode: MODE,
+}
 
-            impl ABCDSR1 {
-                pub(crate) fn abcdsr1(&mut self) -> &$pioy::ABCDSR {
-                    unsafe { &(*$PIOX::ptr()).abcdsr[0] }
-                }
-            }
+impl<const PORT: char, const N: u8, MODE> PinIndex for Pin<PORT, N, MODE> {
+    type Mode = MODE;
 
-            pub struct ABCDSR2 {
-                _0: (),
-            }
+    fn port(&self) -> char {
+        PORT
+    }
 
-            impl ABCDSR2 {
-                pub(crate) fn abcdsr2(&mut self) -> &$pioy::ABCDSR {
-                    unsafe { &(*$PIOX::ptr()).abcdsr[1] }
-                }
-            }
+    fn pin(&self) -> u8 {
+        N
+    }
+}
 
-            pub struct PER {
-                _0: (),
-            }
+impl<MODE> PinIndex for AnyPin<MODE> {
+    type Mode = MODE;
 
-            impl PER {
-                pub(crate) fn per(&mut self) -> &$pioy::PER {
-                    unsafe { &(*$PIOX::ptr()).per }
-                }
-            }
+    fn port(&self) -> char {
+        self.port
+    }
 
-            pub struct PDR {
-                _0: (),
-            }
+    fn pin(&self) -> u8 {
+        self.pin
+    }
+}
 
-            impl PDR {
-                pub(crate) fn pdr(&mut self) -> &$pioy::PDR {
-                    unsafe { &(*$PIOX::ptr()).pdr }
-                }
+impl<const PORT: char, const N: u8, MODE> Pin<PORT, N, MODE> {
+    /// Erases this pin's port and pin number into a runtime value.
+    ///
+    /// Carries the pin's mode along with it, so a configured [`Dynamic`]
+    /// pin keeps its current [`DynamicMode`] instead of losing it.
+    pub fn downgrade(self) -> AnyPin<MODE> {
+        AnyPin {
+            port: PORT,
+            pin: N,
+            mode: self.mode,
+        }
+    }
+
+    /// Alias for [`Pin::downgrade`]
+    pub fn degrade(self) -> AnyPin<MODE> {
+        self.downgrade()
+    }
+}
+
+// `OutputPin`/`StatefulOutputPin`/`InputPin` are foreign traits, so they must
+// be implemented for the two concrete local pin types (`Pin`, `AnyPin`)
+// rather than as a blanket impl over `PinIndex` — a bare type parameter can't
+// stand in for "some local type" under the orphan rule.
+
+impl<const PORT: char, const N: u8, M> OutputPin for Pin<PORT, N, Output<M>> {
+    type Error = Infallible;
+
+    fn set_high(&mut self) -> Result<(), Infallible> {
+        // NOTE(unsafe) atomic write to a stateless register
+        unsafe { (*pio_ptr(self.port())).sodr.write(|w| w.bits(1 << self.pin())) }
+        Ok(())
+    }
+
+    fn set_low(&mut self) -> Result<(), Infallible> {
+        // NOTE(unsafe) atomic write to a stateless register
+        unsafe { (*pio_ptr(self.port())).codr.write(|w| w.bits(1 << self.pin())) }
+        Ok(())
+    }
+}
+
+impl<M> OutputPin for AnyPin<Output<M>> {
+    type Error = Infallible;
+
+    fn set_high(&mut self) -> Result<(), Infallible> {
+        // NOTE(unsafe) atomic write to a stateless register
+        unsafe { (*pio_ptr(self.port())).sodr.write(|w| w.bits(1 << self.pin())) }
+        Ok(())
+    }
+
+    fn set_low(&mut self) -> Result<(), Infallible> {
+        // NOTE(unsafe) atomic write to a stateless register
+        unsafe { (*pio_ptr(self.port())).codr.write(|w| w.bits(1 << self.pin())) }
+        Ok(())
+    }
+}
+
+impl<const PORT: char, const N: u8, M> StatefulOutputPin for Pin<PORT, N, Output<M>> {
+    fn is_set_high(&self) -> Result<bool, Infallible> {
+        // NOTE(unsafe) atomic read with no side effects
+        Ok(unsafe { (*pio_ptr(self.port())).odsr.read().bits() & (1 << self.pin()) != 0 })
+    }
+
+    fn is_set_low(&self) -> Result<bool, Infallible> {
+        self.is_set_high().map(|high| !high)
+    }
+}
+
+impl<M> StatefulOutputPin for AnyPin<Output<M>> {
+    fn is_set_high(&self) -> Result<bool, Infallible> {
+        // NOTE(unsafe) atomic read with no side effects
+        Ok(unsafe { (*pio_ptr(self.port())).odsr.read().bits() & (1 << self.pin()) != 0 })
+    }
+
+    fn is_set_low(&self) -> Result<bool, Infallible> {
+        self.is_set_high().map(|high| !high)
+    }
+}
+
+impl<const PORT: char, const N: u8, M> toggleable::Default for Pin<PORT, N, Output<M>> {}
+impl<M> toggleable::Default for AnyPin<Output<M>> {}
+
+impl<const PORT: char, const N: u8, M> InputPin for Pin<PORT, N, Input<M>> {
+    type Error = Infallible;
+
+    fn is_high(&self) -> Result<bool, Infallible> {
+        // NOTE(unsafe) atomic read with no side effects
+        Ok(unsafe { (*pio_ptr(self.port())).pdsr.read().bits() & (1 << self.pin()) != 0 })
+    }
+
+    fn is_low(&self) -> Result<bool, Infallible> {
+        self.is_high().map(|high| !high)
+    }
+}
+
+impl<M> InputPin for AnyPin<Input<M>> {
+    type Error = Infallible;
+
+    fn is_high(&self) -> Result<bool, Infallible> {
+        // NOTE(unsafe) atomic read with no side effects
+        Ok(unsafe { (*pio_ptr(self.port())).pdsr.read().bits() & (1 << self.pin()) != 0 })
+    }
+
+    fn is_low(&self) -> Result<bool, Infallible> {
+        self.is_high().map(|high| !high)
+    }
+}
+
+macro_rules! port_register {
+    ($Token:ident, $reg:ident, $doc:expr) => {
+        #[doc = $doc]
+        pub struct $Token<const PORT: char> {
+            _0: (),
+        }
+
+        impl<const PORT: char> $Token<PORT> {
+            pub(crate) fn reg(&mut self) -> &atsam4s16b::pioa::$Token {
+                unsafe { &(*pio_ptr(PORT)).$reg }
             }
+        }
+    };
+}
+
+/// Opaque ABCDSR1 register
+pub struct ABCDSR1<const PORT: char> {
+    _0: (),
+}
+
+/// Opaque ABCDSR2 register
+pub struct ABCDSR2<const PORT: char> {
+    _0: (),
+}
+
+port_register!(PER, per, "Opaque PER register");
+port_register!(PDR, pdr, "Opaque PDR register");
+port_register!(OER, oer, "Opaque OER register");
+port_register!(ODR, odr, "Opaque ODR register");
+port_register!(PUER, puer, "Opaque PUER register");
+port_register!(PUDR, pudr, "Opaque PUDR register");
+port_register!(PPDER, ppder, "Opaque PPDER register");
+port_register!(PPDDR, ppddr, "Opaque PPDDR register");
+port_register!(IFER, ifer, "Opaque IFER register");
+port_register!(IFDR, ifdr, "Opaque IFDR register");
+port_register!(MDER, mder, "Opaque MDER register");
+port_register!(MDDR, mddr, "Opaque MDDR register");
+port_register!(IER, ier, "Opaque IER register");
+port_register!(IDR, idr, "Opaque IDR register");
+port_register!(AIMER, aimer, "Opaque AIMER register");
+port_register!(AIMDR, aimdr, "Opaque AIMDR register");
+port_register!(ESR, esr, "Opaque ESR register");
+port_register!(LSR, lsr, "Opaque LSR register");
+port_register!(REHLSR, rehlsr, "Opaque REHLSR register");
+port_register!(FELLSR, fellsr, "Opaque FELLSR register");
+port_register!(ISR, isr, "Opaque ISR register");
+
+// ABCDSR1/ABCDSR2 share the two-element `abcdsr` register array, so their
+// accessors are written out by hand instead of through `port_register!`.
+impl<const PORT: char> ABCDSR1<PORT> {
+    pub(crate) fn abcdsr1(&mut self) -> &atsam4s16b::pioa::ABCDSR {
+        unsafe { &(*pio_ptr(PORT)).abcdsr[0] }
+    }
+}
+
+impl<const PORT: char> ABCDSR2<PORT> {
+    pub(crate) fn abcdsr2(&mut self) -> &atsam4s16b::pioa::ABCDSR {
+        unsafe { &(*pio_ptr(PORT)).abcdsr[1] }
+    }
+}
+
+/// Reads the pending, unmasked interrupt status for a PIO port.
+///
+/// NOTE(read-to-clear): reading `ISR` clears every currently pending bit as
+/// a side effect, so a port's interrupt handler should call this once per
+/// interrupt and test the returned bitmask against each pin, rather than
+/// clearing it more than once.
+pub fn interrupt_status<const PORT: char>(isr: &mut ISR<PORT>) -> u32 {
+    isr.reg().read().bits()
+}
+
+impl<const PORT: char, const N: u8, MODE> Pin<PORT, N, MODE> {
+    pub fn into_peripheralA(
+        self,
+        pdr: &mut PDR<PORT>,
+        abcdsr1: &mut ABCDSR1<PORT>,
+        abcdsr2: &mut ABCDSR2<PORT>,
+    ) -> Pin<PORT, N, PeripheralA> {
+        // Disable PIO for this pin (enables peripheral)
+        pdr.reg().write(|w| unsafe { w.bits(1 << N) });
+
+        // Set ABCDSR1 to 0 and ABCDSR2 to 0 for Peripheral A.
+        abcdsr1
+            .abcdsr1()
+            .modify(|r, w| unsafe { w.bits((r.bits() & !(1 << N)) & !(1 << N)) });
+        abcdsr2
+            .abcdsr2()
+            .modify(|r, w| unsafe { w.bits((r.bits() & !(1 << N)) & !(1 << N)) });
+
+        Pin { mode: PeripheralA }
+    }
+
+    pub fn into_peripheralB(
+        self,
+        pdr: &mut PDR<PORT>,
+        abcdsr1: &mut ABCDSR1<PORT>,
+        abcdsr2: &mut ABCDSR2<PORT>,
+    ) -> Pin<PORT, N, PeripheralB> {
+        // Disable PIO for this pin (enables peripheral)
+        pdr.reg().write(|w| unsafe { w.bits(1 << N) });
+
+        // Set ABCDSR1 to 0 and ABCDSR2 to 1 for Peripheral B.
+        abcdsr1
+            .abcdsr1()
+            .modify(|r, w| unsafe { w.bits((r.bits() & !(1 << N)) & !(1 << N)) });
+        abcdsr2
+            .abcdsr2()
+            .modify(|r, w| unsafe { w.bits((r.bits() & !(1 << N)) | (1 << N)) });
+
+        Pin { mode: PeripheralB }
+    }
+
+    pub fn into_peripheralC(
+        self,
+        pdr: &mut PDR<PORT>,
+        abcdsr1: &mut ABCDSR1<PORT>,
+        abcdsr2: &mut ABCDSR2<PORT>,
+    ) -> Pin<PORT, N, PeripheralC> {
+        // Disable PIO for this pin (enables peripheral)
+        pdr.reg().write(|w| unsafe { w.bits(1 << N) });
+
+        // Set ABCDSR1 to 1 and ABCDSR2 to 0 for Peripheral C.
+        abcdsr1
+            .abcdsr1()
+            .modify(|r, w| unsafe { w.bits((r.bits() & !(1 << N)) | (1 << N)) });
+        abcdsr2
+            .abcdsr2()
+            .modify(|r, w| unsafe { w.bits((r.bits() & !(1 << N)) & !(1 << N)) });
+
+        Pin { mode: PeripheralC }
+    }
+
+    pub fn into_peripheralD(
+        self,
+        pdr: &mut PDR<PORT>,
+        abcdsr1: &mut ABCDSR1<PORT>,
+        abcdsr2: &mut ABCDSR2<PORT>,
+    ) -> Pin<PORT, N, PeripheralD> {
+        // Disable PIO for this pin (enables peripheral)
+        pdr.reg().write(|w| unsafe { w.bits(1 << N) });
+
+        // Set ABCDSR1 to 1 and ABCDSR2 to 1 for Peripheral D.
+        abcdsr1
+            .abcdsr1()
+            .modify(|r, w| unsafe { w.bits((r.bits() & !(1 << N)) | (1 << N)) });
+        abcdsr2
+            .abcdsr2()
+            .modify(|r, w| unsafe { w.bits((r.bits() & !(1 << N)) | (1 << N)) });
+
+        Pin { mode: PeripheralD }
+    }
+
+    pub fn into_output(self, oer: &mut OER<PORT>) -> Pin<PORT, N, Output<()>> {
+        // Enable output for this pin
+        oer.reg().write(|w| unsafe { w.bits(1 << N) });
+
+        Pin {
+            mode: Output { _mode: PhantomData },
+        }
+    }
 
-            pub struct OER {
-                _0: (),
+    pub fn into_push_pull_output(
+        self,
+        oer: &mut OER<PORT>,
+        mddr: &mut MDDR<PORT>,
+    ) -> Pin<PORT, N, Output<PushPull>> {
+        // Disable the multi-driver (open-drain) capability
+        mddr.reg().write(|w| unsafe { w.bits(1 << N) });
+        // Enable output for this pin
+        oer.reg().write(|w| unsafe { w.bits(1 << N) });
+
+        Pin {
+            mode: Output { _mode: PhantomData },
+        }
+    }
+
+    pub fn into_open_drain_output(
+        self,
+        oer: &mut OER<PORT>,
+        mder: &mut MDER<PORT>,
+    ) -> Pin<PORT, N, Output<OpenDrain>> {
+        // Enable the multi-driver (open-drain) capability
+        mder.reg().write(|w| unsafe { w.bits(1 << N) });
+        // Enable output for this pin
+        oer.reg().write(|w| unsafe { w.bits(1 << N) });
+
+        Pin {
+            mode: Output { _mode: PhantomData },
+        }
+    }
+
+    pub fn into_floating_input(
+        self,
+        per: &mut PER<PORT>,
+        odr: &mut ODR<PORT>,
+        pudr: &mut PUDR<PORT>,
+        ppddr: &mut PPDDR<PORT>,
+    ) -> Pin<PORT, N, Input<Floating>> {
+        // Let the PIO controller drive this pin
+        per.reg().write(|w| unsafe { w.bits(1 << N) });
+        // Disable the output driver so the pin reflects the external level
+        odr.reg().write(|w| unsafe { w.bits(1 << N) });
+        // Disable both pull resistors
+        pudr.reg().write(|w| unsafe { w.bits(1 << N) });
+        ppddr.reg().write(|w| unsafe { w.bits(1 << N) });
+
+        Pin {
+            mode: Input { _mode: PhantomData },
+        }
+    }
+
+    pub fn into_pull_up_input(
+        self,
+        per: &mut PER<PORT>,
+        odr: &mut ODR<PORT>,
+        puer: &mut PUER<PORT>,
+        ppddr: &mut PPDDR<PORT>,
+    ) -> Pin<PORT, N, Input<PullUp>> {
+        // Let the PIO controller drive this pin
+        per.reg().write(|w| unsafe { w.bits(1 << N) });
+        // Disable the output driver so the pin reflects the external level
+        odr.reg().write(|w| unsafe { w.bits(1 << N) });
+        // The SAM4S forbids both pull resistors being enabled at once
+        ppddr.reg().write(|w| unsafe { w.bits(1 << N) });
+        puer.reg().write(|w| unsafe { w.bits(1 << N) });
+
+        Pin {
+            mode: Input { _mode: PhantomData },
+        }
+    }
+
+    pub fn into_pull_down_input(
+        self,
+        per: &mut PER<PORT>,
+        odr: &mut ODR<PORT>,
+        pudr: &mut PUDR<PORT>,
+        ppder: &mut PPDER<PORT>,
+    ) -> Pin<PORT, N, Input<PullDown>> {
+        // Let the PIO controller drive this pin
+        per.reg().write(|w| unsafe { w.bits(1 << N) });
+        // Disable the output driver so the pin reflects the external level
+        odr.reg().write(|w| unsafe { w.bits(1 << N) });
+        // The SAM4S forbids both pull resistors being enabled at once
+        pudr.reg().write(|w| unsafe { w.bits(1 << N) });
+        ppder.reg().write(|w| unsafe { w.bits(1 << N) });
+
+        Pin {
+            mode: Input { _mode: PhantomData },
+        }
+    }
+
+    /// Converts this pin into a [`Dynamic`] pin whose direction and pull
+    /// resistors are chosen at runtime, starting out as a floating input.
+    pub fn into_dynamic(
+        self,
+        per: &mut PER<PORT>,
+        odr: &mut ODR<PORT>,
+        pudr: &mut PUDR<PORT>,
+        ppddr: &mut PPDDR<PORT>,
+    ) -> Pin<PORT, N, Dynamic> {
+        // Let the PIO controller drive this pin, as a floating input
+        per.reg().write(|w| unsafe { w.bits(1 << N) });
+        odr.reg().write(|w| unsafe { w.bits(1 << N) });
+        pudr.reg().write(|w| unsafe { w.bits(1 << N) });
+        ppddr.reg().write(|w| unsafe { w.bits(1 << N) });
+
+        Pin {
+            mode: Dynamic {
+                mode: DynamicMode::InputFloating,
+            },
+        }
+    }
+}
+
+impl<const PORT: char, const N: u8, MODE> Pin<PORT, N, Input<MODE>> {
+    /// Suppresses pulses on the input shorter than one PIO clock cycle
+    pub fn enable_glitch_filter(&mut self, ifer: &mut IFER<PORT>) {
+        ifer.reg().write(|w| unsafe { w.bits(1 << N) });
+    }
+
+    pub fn disable_glitch_filter(&mut self, ifdr: &mut IFDR<PORT>) {
+        ifdr.reg().write(|w| unsafe { w.bits(1 << N) });
+    }
+
+    /// Configures this pin as an interrupt source and unmasks it.
+    ///
+    /// For [`Interrupt::AnyEdge`] the "additional interrupt modes" are left
+    /// disabled (`aimdr`), which is the PIO controller's default any-edge
+    /// behavior. The other variants enable additional interrupt modes
+    /// (`aimer`), select edge-vs-level detection (`esr`/`lsr`), and select
+    /// which edge/level (`rehlsr`/`fellsr`) before unmasking the pin (`ier`).
+    pub fn make_interrupt_source(
+        &mut self,
+        cfg: Interrupt,
+        ier: &mut IER<PORT>,
+        aimer: &mut AIMER<PORT>,
+        aimdr: &mut AIMDR<PORT>,
+        esr: &mut ESR<PORT>,
+        lsr: &mut LSR<PORT>,
+        rehlsr: &mut REHLSR<PORT>,
+        fellsr: &mut FELLSR<PORT>,
+    ) {
+        match cfg {
+            Interrupt::AnyEdge => {
+                aimdr.reg().write(|w| unsafe { w.bits(1 << N) });
+            }
+            Interrupt::RisingEdge => {
+                aimer.reg().write(|w| unsafe { w.bits(1 << N) });
+                esr.reg().write(|w| unsafe { w.bits(1 << N) });
+                rehlsr.reg().write(|w| unsafe { w.bits(1 << N) });
+            }
+            Interrupt::FallingEdge => {
+                aimer.reg().write(|w| unsafe { w.bits(1 << N) });
+                esr.reg().write(|w| unsafe { w.bits(1 << N) });
+                fellsr.reg().write(|w| unsafe { w.bits(1 << N) });
+            }
+            Interrupt::HighLevel => {
+                aimer.reg().write(|w| unsafe { w.bits(1 << N) });
+                lsr.reg().write(|w| unsafe { w.bits(1 << N) });
+                rehlsr.reg().write(|w| unsafe { w.bits(1 << N) });
+            }
+            Interrupt::LowLevel => {
+                aimer.reg().write(|w| unsafe { w.bits(1 << N) });
+                lsr.reg().write(|w| unsafe { w.bits(1 << N) });
+                fellsr.reg().write(|w| unsafe { w.bits(1 << N) });
             }
+        }
 
-            impl OER {
-                pub(crate) fn oer(&mut self) -> &$pioy::OER {
-                    unsafe { &(*$PIOX::ptr()).oer }
-                }
+        // Unmask this pin's interrupt
+        ier.reg().write(|w| unsafe { w.bits(1 << N) });
+    }
+
+    /// Masks this pin's interrupt so it no longer reaches the NVIC
+    pub fn disable_interrupt(&mut self, idr: &mut IDR<PORT>) {
+        idr.reg().write(|w| unsafe { w.bits(1 << N) });
+    }
+
+    /// Returns whether this pin's interrupt is currently pending.
+    ///
+    /// NOTE(read-to-clear): `ISR` clears every pending bit on the port as a
+    /// side effect of being read, so prefer [`interrupt_status`] in a
+    /// port-wide interrupt handler and only use this for a single pin in
+    /// isolation.
+    pub fn is_interrupt_pending(&self, isr: &mut ISR<PORT>) -> bool {
+        isr.reg().read().bits() & (1 << N) != 0
+    }
+
+    /// Clears this pin's pending interrupt bit.
+    ///
+    /// This is simply the read-to-clear read of `ISR`; see the note on
+    /// [`is_interrupt_pending`].
+    pub fn clear_interrupt_pending_bit(&mut self, isr: &mut ISR<PORT>) {
+        let _ = isr.reg().read();
+    }
+}
+
+impl<const PORT: char, const N: u8> Pin<PORT, N, Dynamic> {
+    /// Reconfigures this pin as a floating input
+    pub fn make_floating_input(
+        &mut self,
+        per: &mut PER<PORT>,
+        odr: &mut ODR<PORT>,
+        pudr: &mut PUDR<PORT>,
+        ppddr: &mut PPDDR<PORT>,
+    ) {
+        per.reg().write(|w| unsafe { w.bits(1 << N) });
+        odr.reg().write(|w| unsafe { w.bits(1 << N) });
+        pudr.reg().write(|w| unsafe { w.bits(1 << N) });
+        ppddr.reg().write(|w| unsafe { w.bits(1 << N) });
+
+        self.mode.mode = DynamicMode::InputFloating;
+    }
+
+    /// Reconfigures this pin as a pulled-up input
+    pub fn make_pull_up_input(
+        &mut self,
+        per: &mut PER<PORT>,
+        odr: &mut ODR<PORT>,
+        puer: &mut PUER<PORT>,
+        ppddr: &mut PPDDR<PORT>,
+    ) {
+        per.reg().write(|w| unsafe { w.bits(1 << N) });
+        odr.reg().write(|w| unsafe { w.bits(1 << N) });
+        // The SAM4S forbids both pull resistors being enabled at once
+        ppddr.reg().write(|w| unsafe { w.bits(1 << N) });
+        puer.reg().write(|w| unsafe { w.bits(1 << N) });
+
+        self.mode.mode = DynamicMode::InputPullUp;
+    }
+
+    /// Reconfigures this pin as a pulled-down input
+    pub fn make_pull_down_input(
+        &mut self,
+        per: &mut PER<PORT>,
+        odr: &mut ODR<PORT>,
+        pudr: &mut PUDR<PORT>,
+        ppder: &mut PPDER<PORT>,
+    ) {
+        per.reg().write(|w| unsafe { w.bits(1 << N) });
+        odr.reg().write(|w| unsafe { w.bits(1 << N) });
+        // The SAM4S forbids both pull resistors being enabled at once
+        pudr.reg().write(|w| unsafe { w.bits(1 << N) });
+        ppder.reg().write(|w| unsafe { w.bits(1 << N) });
+
+        self.mode.mode = DynamicMode::InputPullDown;
+    }
+
+    /// Reconfigures this pin as a push-pull output
+    pub fn make_push_pull_output(&mut self, oer: &mut OER<PORT>, mddr: &mut MDDR<PORT>) {
+        mddr.reg().write(|w| unsafe { w.bits(1 << N) });
+        oer.reg().write(|w| unsafe { w.bits(1 << N) });
+
+        self.mode.mode = DynamicMode::OutputPushPull;
+    }
+
+    /// Reconfigures this pin as an open-drain (multi-driver) output
+    pub fn make_open_drain_output(&mut self, oer: &mut OER<PORT>, mder: &mut MDER<PORT>) {
+        mder.reg().write(|w| unsafe { w.bits(1 << N) });
+        oer.reg().write(|w| unsafe { w.bits(1 << N) });
+
+        self.mode.mode = DynamicMode::OutputOpenDrain;
+    }
+
+    /// Drives the pin high, if it's currently configured as an output
+    pub fn set_high(&mut self) -> Result<(), PinModeError> {
+        match self.mode.mode {
+            DynamicMode::OutputPushPull | DynamicMode::OutputOpenDrain => {
+                // NOTE(unsafe) atomic write to a stateless register
+                unsafe { (*pio_ptr(PORT)).sodr.write(|w| w.bits(1 << N)) }
+                Ok(())
             }
+            _ => Err(PinModeError::NotOutput),
+        }
+    }
 
-            pub struct ODR {
-                _0: (),
+    /// Drives the pin low, if it's currently configured as an output
+    pub fn set_low(&mut self) -> Result<(), PinModeError> {
+        match self.mode.mode {
+            DynamicMode::OutputPushPull | DynamicMode::OutputOpenDrain => {
+                // NOTE(unsafe) atomic write to a stateless register
+                unsafe { (*pio_ptr(PORT)).codr.write(|w| w.bits(1 << N)) }
+                Ok(())
             }
+            _ => Err(PinModeError::NotOutput),
+        }
+    }
 
-            impl ODR {
-                pub(crate) fn odr(&mut self) -> &$pioy::ODR {
-                    unsafe { &(*$PIOX::ptr()).odr }
-                }
+    /// Reads whether the pin is currently high, if it's configured as an input
+    pub fn is_high(&self) -> Result<bool, PinModeError> {
+        match self.mode.mode {
+            DynamicMode::InputFloating | DynamicMode::InputPullUp | DynamicMode::InputPullDown => {
+                // NOTE(unsafe) atomic read with no side effects
+                Ok(unsafe { (*pio_ptr(PORT)).pdsr.read().bits() & (1 << N) != 0 })
             }
+            _ => Err(PinModeError::NotInput),
+        }
+    }
+
+    /// Reads whether the pin is currently low, if it's configured as an input
+    pub fn is_low(&self) -> Result<bool, PinModeError> {
+        self.is_high().map(|high| !high)
+    }
+}
+
+impl AnyPin<Dynamic> {
+    /// Reconfigures this pin as a floating input
+    pub fn make_floating_input(&mut self) {
+        let mask = 1 << self.pin;
+        unsafe {
+            (*pio_ptr(self.port)).per.write(|w| w.bits(mask));
+            (*pio_ptr(self.port)).odr.write(|w| w.bits(mask));
+            (*pio_ptr(self.port)).pudr.write(|w| w.bits(mask));
+            (*pio_ptr(self.port)).ppddr.write(|w| w.bits(mask));
+        }
+
+        self.mode.mode = DynamicMode::InputFloating;
+    }
+
+    /// Reconfigures this pin as a pulled-up input
+    pub fn make_pull_up_input(&mut self) {
+        let mask = 1 << self.pin;
+        unsafe {
+            (*pio_ptr(self.port)).per.write(|w| w.bits(mask));
+            (*pio_ptr(self.port)).odr.write(|w| w.bits(mask));
+            // The SAM4S forbids both pull resistors being enabled at once
+            (*pio_ptr(self.port)).ppddr.write(|w| w.bits(mask));
+            (*pio_ptr(self.port)).puer.write(|w| w.bits(mask));
+        }
+
+        self.mode.mode = DynamicMode::InputPullUp;
+    }
+
+    /// Reconfigures this pin as a pulled-down input
+    pub fn make_pull_down_input(&mut self) {
+        let mask = 1 << self.pin;
+        unsafe {
+            (*pio_ptr(self.port)).per.write(|w| w.bits(mask));
+            (*pio_ptr(self.port)).odr.write(|w| w.bits(mask));
+            // The SAM4S forbids both pull resistors being enabled at once
+            (*pio_ptr(self.port)).pudr.write(|w| w.bits(mask));
+            (*pio_ptr(self.port)).ppder.write(|w| w.bits(mask));
+        }
 
-            pub struct $PXx<MODE> {
-                i: u8,
-                _mode: PhantomData<MODE>,
+        self.mode.mode = DynamicMode::InputPullDown;
+    }
+
+    /// Reconfigures this pin as a push-pull output
+    pub fn make_push_pull_output(&mut self) {
+        let mask = 1 << self.pin;
+        unsafe {
+            (*pio_ptr(self.port)).mddr.write(|w| w.bits(mask));
+            (*pio_ptr(self.port)).oer.write(|w| w.bits(mask));
+        }
+
+        self.mode.mode = DynamicMode::OutputPushPull;
+    }
+
+    /// Reconfigures this pin as an open-drain (multi-driver) output
+    pub fn make_open_drain_output(&mut self) {
+        let mask = 1 << self.pin;
+        unsafe {
+            (*pio_ptr(self.port)).mder.write(|w| w.bits(mask));
+            (*pio_ptr(self.port)).oer.write(|w| w.bits(mask));
+        }
+
+        self.mode.mode = DynamicMode::OutputOpenDrain;
+    }
+
+    /// Drives the pin high, if it's currently configured as an output
+    pub fn set_high(&mut self) -> Result<(), PinModeError> {
+        match self.mode.mode {
+            DynamicMode::OutputPushPull | DynamicMode::OutputOpenDrain => {
+                // NOTE(unsafe) atomic write to a stateless register
+                unsafe { (*pio_ptr(self.port)).sodr.write(|w| w.bits(1 << self.pin)) }
+                Ok(())
             }
+            _ => Err(PinModeError::NotOutput),
+        }
+    }
 
-            impl<MODE> OutputPin for $PXx<Output<MODE>> {
-                fn set_high(&mut self) {
-                    // NOTE(unsafe) atomic write to a stateless register
-                    unsafe { (*$PIOX::ptr()).sodr.write(|w| w.bits(1 << self.i)) }
-                }
+    /// Drives the pin low, if it's currently configured as an output
+    pub fn set_low(&mut self) -> Result<(), PinModeError> {
+        match self.mode.mode {
+            DynamicMode::OutputPushPull | DynamicMode::OutputOpenDrain => {
+                // NOTE(unsafe) atomic write to a stateless register
+                unsafe { (*pio_ptr(self.port)).codr.write(|w| w.bits(1 << self.pin)) }
+                Ok(())
+            }
+            _ => Err(PinModeError::NotOutput),
+        }
+    }
 
-                fn set_low(&mut self) {
-                    // NOTE(unsafe) atomic write to a stateless register
-                    unsafe { (*$PIOX::ptr()).codr.write(|w| w.bits(1 << self.i)) }
-                }
+    /// Reads whether the pin is currently high, if it's configured as an input
+    pub fn is_high(&self) -> Result<bool, PinModeError> {
+        match self.mode.mode {
+            DynamicMode::InputFloating | DynamicMode::InputPullUp | DynamicMode::InputPullDown => {
+                // NOTE(unsafe) atomic read with no side effects
+                Ok(unsafe { (*pio_ptr(self.port)).pdsr.read().bits() & (1 << self.pin) != 0 })
             }
+            _ => Err(PinModeError::NotInput),
+        }
+    }
 
-            $(
-                /// Pin
-                pub struct $PXi<MODE> {
-                    _mode: PhantomData<MODE>,
-                }
+    /// Reads whether the pin is currently low, if it's configured as an input
+    pub fn is_low(&self) -> Result<bool, PinModeError> {
+        self.is_high().map(|high| !high)
+    }
+}
 
-                impl<MODE> $PXi<MODE> {
-                    pub fn into_peripheralA(
-                        self,
-                        pdr: &mut PDR,
-                        abcdsr1: &mut ABCDSR1,
-                        abcdsr2: &mut ABCDSR2,
-                    ) -> $PXi<PeripheralA> {
-                        // Disable PIO for this pin (enables peripheral)
-                        pdr.pdr().write(|w| unsafe { w.bits(1 << $i) });
-
-                        // Set ABCDSR1 to 0 and ABCDSR2 to 0 for Peripheral A.
-                        abcdsr1.abcdsr1().modify(|r, w| unsafe {
-                            w.bits((r.bits() & !(1 << $i)) & !(1 << $i))
-                        });
-                        abcdsr2.abcdsr2().modify(|r, w| unsafe {
-                            w.bits((r.bits() & !(1 << $i)) & !(1 << $i))
-                        });
-
-                        $PXi { _mode: PhantomData }
-                    }
+macro_rules! pio {
+    ($PIOX:ident, $piox:ident, $port:expr, $PID:expr, [
+        $($PXi:ident: ($pxi:ident, $i:expr, $MODE:ty),)+
+    ]) => {
+        /// PIO
+        pub mod $piox {
+            pub use atsam4s16b::$PIOX;
 
-                    pub fn into_peripheralB(
-                        self,
-                        pdr: &mut PDR,
-                        abcdsr1: &mut ABCDSR1,
-                        abcdsr2: &mut ABCDSR2,
-                    ) -> $PXi<PeripheralB> {
-                        // Disable PIO for this pin (enables peripheral)
-                        pdr.pdr().write(|w| unsafe { w.bits(1 << $i) });
-
-                        // Set ABCDSR1 to 0 and ABCDSR2 to 1 for Peripheral B.
-                        abcdsr1.abcdsr1().modify(|r, w| unsafe {
-                            w.bits((r.bits() & !(1 << $i)) & !(1 << $i))
-                        });
-                        abcdsr2.abcdsr2().modify(|r, w| unsafe {
-                            w.bits((r.bits() & !(1 << $i)) | (1 << $i))
-                        });
-
-                        $PXi { _mode: PhantomData }
-                    }
+            use crate::pmc::Pmc;
 
-                    pub fn into_peripheralC(
-                        self,
-                        pdr: &mut PDR,
-                        abcdsr1: &mut ABCDSR1,
-                        abcdsr2: &mut ABCDSR2,
-                    ) -> $PXi<PeripheralC> {
-                        // Disable PIO for this pin (enables peripheral)
-                        pdr.pdr().write(|w| unsafe { w.bits(1 << $i) });
-
-                        // Set ABCDSR1 to 1 and ABCDSR2 to 0 for Peripheral C.
-                        abcdsr1.abcdsr1().modify(|r, w| unsafe {
-                            w.bits((r.bits() & !(1 << $i)) | (1 << $i))
-                        });
-                        abcdsr2.abcdsr2().modify(|r, w| unsafe {
-                            w.bits((r.bits() & !(1 << $i)) & !(1 << $i))
-                        });
-
-                        $PXi { _mode: PhantomData }
-                    }
+            use core::marker::PhantomData;
 
-                    pub fn into_peripheralD(
-                        self,
-                        pdr: &mut PDR,
-                        abcdsr1: &mut ABCDSR1,
-                        abcdsr2: &mut ABCDSR2,
-                    ) -> $PXi<PeripheralD> {
-                        // Disable PIO for this pin (enables peripheral)
-                        pdr.pdr().write(|w| unsafe { w.bits(1 << $i) });
-
-                        // Set ABCDSR1 to 1 and ABCDSR2 to 1 for Peripheral D.
-                        abcdsr1.abcdsr1().modify(|r, w| unsafe {
-                            w.bits((r.bits() & !(1 << $i)) | (1 << $i))
-                        });
-                        abcdsr2.abcdsr2().modify(|r, w| unsafe {
-                            w.bits((r.bits() & !(1 << $i)) | (1 << $i))
-                        });
-
-                        $PXi { _mode: PhantomData }
-                    }
+            use super::{
+                ABCDSR1, ABCDSR2, PER, PDR, OER, ODR, PUER, PUDR, PPDER, PPDDR,
+                IFER, IFDR, MDER, MDDR, IER, IDR, AIMER, AIMDR, ESR, LSR, REHLSR, FELLSR, ISR,
+                GpioExt, Pin, Input, Floating,
+            };
 
-                    pub fn into_output(
-                        self,
-                        oer: &mut OER,
-                    ) -> $PXi<Output<()>> {
-                        // Enable output for this pin
-                        oer.oer().write(|w| unsafe { w.bits(1 << $i) });
+            $(
+                /// Pin
+                pub type $PXi = Pin<$port, $i, $MODE>;
+            )+
 
-                        $PXi { _mode: PhantomData }
-                    }
-                }
+            pub struct Parts {
+                /// Opaque ABCDSR1 register
+                pub abcdsr1: ABCDSR1<$port>,
+                /// Opaque ABCDSR2 register
+                pub abcdsr2: ABCDSR2<$port>,
+                /// Opaque PER register
+                pub per: PER<$port>,
+                /// Opaque PDR register
+                pub pdr: PDR<$port>,
+                /// Opaque OER register
+                pub oer: OER<$port>,
+                /// Opaque ODR register
+                pub odr: ODR<$port>,
+                /// Opaque PUER register
+                pub puer: PUER<$port>,
+                /// Opaque PUDR register
+                pub pudr: PUDR<$port>,
+                /// Opaque PPDER register
+                pub ppder: PPDER<$port>,
+                /// Opaque PPDDR register
+                pub ppddr: PPDDR<$port>,
+                /// Opaque IFER register
+                pub ifer: IFER<$port>,
+                /// Opaque IFDR register
+                pub ifdr: IFDR<$port>,
+                /// Opaque MDER register
+                pub mder: MDER<$port>,
+                /// Opaque MDDR register
+                pub mddr: MDDR<$port>,
+                /// Opaque IER register
+                pub ier: IER<$port>,
+                /// Opaque IDR register
+                pub idr: IDR<$port>,
+                /// Opaque AIMER register
+                pub aimer: AIMER<$port>,
+                /// Opaque AIMDR register
+                pub aimdr: AIMDR<$port>,
+                /// Opaque ESR register
+                pub esr: ESR<$port>,
+                /// Opaque LSR register
+                pub lsr: LSR<$port>,
+                /// Opaque REHLSR register
+                pub rehlsr: REHLSR<$port>,
+                /// Opaque FELLSR register
+                pub fellsr: FELLSR<$port>,
+                /// Opaque ISR register
+                pub isr: ISR<$port>,
 
-                impl<MODE> $PXi<Output<MODE>> {
-                    pub fn downgrade(self) -> $PXx<Output<MODE>> {
-                        $PXx {
-                            i: $i,
-                            _mode: self._mode,
-                        }
-                    }
-                }
+                $(
+                    /// Pin
+                    pub $pxi: $PXi,
+                )+
+            }
 
-                impl<MODE> OutputPin for $PXi<Output<MODE>> {
-                    fn set_high(&mut self) {
-                        // NOTE(unsafe) atomic write to a stateless register
-                        unsafe { (*$PIOX::ptr()).sodr.write(|w| w.bits(1 << $i)) }
-                    }
+            impl GpioExt for $PIOX {
+                type Parts = Parts;
+
+                fn split(self, pmc: &mut Pmc) -> Parts {
+                    // The PIO controller produces no input readings and
+                    // cannot glitch-filter until its clock is enabled
+                    pmc.enable_peripheral_clock($PID);
 
-                    fn set_low(&mut self) {
-                        // NOTE(unsafe) atomic write to a stateless register
-                        unsafe { (*$PIOX::ptr()).codr.write(|w| w.bits(1 << $i)) }
+                    Parts {
+                        abcdsr1: ABCDSR1 { _0: () },
+                        abcdsr2: ABCDSR2 { _0: () },
+                        per: PER { _0: () },
+                        pdr: PDR { _0: () },
+                        oer: OER { _0: () },
+                        odr: ODR { _0: () },
+                        puer: PUER { _0: () },
+                        pudr: PUDR { _0: () },
+                        ppder: PPDER { _0: () },
+                        ppddr: PPDDR { _0: () },
+                        ifer: IFER { _0: () },
+                        ifdr: IFDR { _0: () },
+                        mder: MDER { _0: () },
+                        mddr: MDDR { _0: () },
+                        ier: IER { _0: () },
+                        idr: IDR { _0: () },
+                        aimer: AIMER { _0: () },
+                        aimdr: AIMDR { _0: () },
+                        esr: ESR { _0: () },
+                        lsr: LSR { _0: () },
+                        rehlsr: REHLSR { _0: () },
+                        fellsr: FELLSR { _0: () },
+                        isr: ISR { _0: () },
+                        $(
+                            $pxi: Pin { mode: Input { _mode: PhantomData } },
+                        )+
                     }
                 }
-            )+
+            }
         }
     }
 }
 
-pio! { PIOA, pioa, pioa, PAx, [
+pio! { PIOA, pioa, 'A', 11, [
     PA0: (pa0, 0, Input<Floating>),
     PA1: (pa1, 1, Input<Floating>),
     PA2: (pa2, 2, Input<Floating>),
@@ -322,7 +1037,7 @@ pio! { PIOA, pioa, pioa, PAx, [
     PA31: (pa31, 31, Input<Floating>),
 ]}
 
-pio! { PIOB, piob, piob, PBx, [
+pio! { PIOB, piob, 'B', 12, [
     PB0: (pb0, 0, Input<Floating>),
     PB1: (pb1, 1, Input<Floating>),
     PB2: (pb2, 2, Input<Floating>),
@@ -357,7 +1072,7 @@ pio! { PIOB, piob, piob, PBx, [
     PB31: (pb31, 31, Input<Floating>),
 ]}
 
-pio! { PIOC, pioc, pioc, PCx, [
+pio! { PIOC, pioc, 'C', 13, [
     PC0: (pc0, 0, Input<Floating>),
     PC1: (pc1, 1, Input<Floating>),
     PC2: (pc2, 2, Input<Floating>),
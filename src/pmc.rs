@@ -0,0 +1,33 @@
+//! Power Management Controller
+
+use atsam4s16b::PMC;
+
+/// Constrained PMC peripheral
+///
+/// Peripherals such as the PIO controllers produce no input readings and
+/// cannot glitch-filter until their peripheral clock is enabled here, so
+/// `GpioExt::split` requires a `&mut Pmc` to gate the relevant clock as
+/// part of the split.
+pub struct Pmc {
+    pub(crate) pmc: PMC,
+}
+
+impl Pmc {
+    /// Enables the peripheral clock identified by `pid` in `PMC_PCER0`
+    pub(crate) fn enable_peripheral_clock(&mut self, pid: u8) {
+        // NOTE(unsafe) atomic write to a stateless register
+        self.pmc.pcer0.write(|w| unsafe { w.bits(1 << pid) });
+    }
+}
+
+/// Extension trait to constrain the `PMC` peripheral
+pub trait PmcExt {
+    /// Constrains the `PMC` peripheral to play nicely with the other abstractions
+    fn constrain(self) -> Pmc;
+}
+
+impl PmcExt for PMC {
+    fn constrain(self) -> Pmc {
+        Pmc { pmc: self }
+    }
+}
@@ -0,0 +1,185 @@
+//! SPI master driver
+//!
+//! Built on top of the `gpio` module's `PeripheralA` type state: the MISO,
+//! MOSI and SPCK pins passed to [`Spi::new`] must already be configured into
+//! their SPI peripheral function. The type system checks only that each pin
+//! is in the `PeripheralA` state; it does not check which port and pin
+//! number is passed for which signal.
+
+use embedded_hal::blocking::spi::{Transfer, Write};
+use embedded_hal::spi::{FullDuplex, Phase, Polarity};
+
+use atsam4s16b::SPI;
+
+use crate::gpio::{PeripheralA, PinIndex};
+
+/// SPI bus configuration
+#[derive(Clone, Copy, Debug)]
+pub struct Config {
+    /// Desired SPI clock frequency, in Hz
+    pub frequency: u32,
+    /// Clock phase
+    pub phase: Phase,
+    /// Clock polarity
+    pub polarity: Polarity,
+}
+
+/// SPI errors
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The requested frequency is too high to reach with any valid SCBR divider
+    FrequencyTooHigh,
+    /// The requested frequency is too low to reach with any valid SCBR divider
+    FrequencyTooLow,
+}
+
+/// SPI master, consuming the MISO/MOSI/SPCK pins already routed to the SPI peripheral function
+pub struct Spi<MISO, MOSI, SCLK> {
+    spi: SPI,
+    pins: (MISO, MOSI, SCLK),
+}
+
+impl<MISO, MOSI, SCLK> Spi<MISO, MOSI, SCLK>
+where
+    MISO: PinIndex<Mode = PeripheralA>,
+    MOSI: PinIndex<Mode = PeripheralA>,
+    SCLK: PinIndex<Mode = PeripheralA>,
+{
+    /// Configures and enables the SPI peripheral as a master.
+    ///
+    /// `mck` is the peripheral clock (MCK) frequency, in Hz, used to compute
+    /// the SCBR baud-rate divider for `config.frequency`.
+    pub fn new(
+        spi: SPI,
+        pins: (MISO, MOSI, SCLK),
+        config: Config,
+        mck: u32,
+    ) -> Result<Self, Error> {
+        let scbr = compute_scbr(mck, config.frequency)?;
+
+        // Reset and disable the peripheral before reconfiguring it
+        spi.cr.write(|w| w.swrst().set_bit());
+        spi.cr.write(|w| w.spidis().set_bit());
+
+        // Master mode, fixed peripheral select, mode fault detection disabled
+        spi.mr.write(|w| unsafe {
+            w.mstr().set_bit();
+            w.modfdis().set_bit();
+            w.pcs().bits(0)
+        });
+
+        spi.csr[0].write(|w| unsafe {
+            w.cpol().bit(config.polarity == Polarity::IdleHigh);
+            w.ncpha().bit(config.phase == Phase::CaptureOnFirstTransition);
+            w.scbr().bits(scbr)
+        });
+
+        spi.cr.write(|w| w.spien().set_bit());
+
+        Ok(Spi { spi, pins })
+    }
+
+    /// Releases the SPI peripheral and its pins
+    pub fn free(self) -> (SPI, (MISO, MOSI, SCLK)) {
+        (self.spi, self.pins)
+    }
+}
+
+/// Computes the nearest SCBR divider for `frequency` given the peripheral clock `mck`.
+fn compute_scbr(mck: u32, frequency: u32) -> Result<u8, Error> {
+    // A zero frequency can't be reached by dividing MCK down at all
+    if frequency == 0 {
+        return Err(Error::FrequencyTooLow);
+    }
+
+    // NOTE: SPCK = MCK / SCBR, with SCBR restricted to 1..=255
+    let divider = (mck + frequency / 2) / frequency;
+
+    if divider < 1 {
+        Err(Error::FrequencyTooHigh)
+    } else if divider > 255 {
+        Err(Error::FrequencyTooLow)
+    } else {
+        Ok(divider as u8)
+    }
+}
+
+impl<MISO, MOSI, SCLK> FullDuplex<u8> for Spi<MISO, MOSI, SCLK> {
+    type Error = Error;
+
+    fn read(&mut self) -> nb::Result<u8, Error> {
+        if self.spi.sr.read().rdrf().bit_is_clear() {
+            return Err(nb::Error::WouldBlock);
+        }
+
+        Ok(self.spi.rdr.read().rd().bits() as u8)
+    }
+
+    fn send(&mut self, byte: u8) -> nb::Result<(), Error> {
+        if self.spi.sr.read().tdre().bit_is_clear() {
+            return Err(nb::Error::WouldBlock);
+        }
+
+        self.spi.tdr.write(|w| unsafe { w.td().bits(byte as u16) });
+        Ok(())
+    }
+}
+
+impl<MISO, MOSI, SCLK> Transfer<u8> for Spi<MISO, MOSI, SCLK> {
+    type Error = Error;
+
+    fn transfer<'w>(&mut self, words: &'w mut [u8]) -> Result<&'w [u8], Error> {
+        for byte in words.iter_mut() {
+            nb::block!(self.send(*byte))?;
+            *byte = nb::block!(self.read())?;
+        }
+
+        Ok(words)
+    }
+}
+
+impl<MISO, MOSI, SCLK> Write<u8> for Spi<MISO, MOSI, SCLK> {
+    type Error = Error;
+
+    fn write(&mut self, words: &[u8]) -> Result<(), Error> {
+        for &byte in words {
+            nb::block!(self.send(byte))?;
+            nb::block!(self.read())?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zero_frequency_is_rejected_instead_of_dividing_by_zero() {
+        assert_eq!(compute_scbr(120_000_000, 0), Err(Error::FrequencyTooLow));
+    }
+
+    #[test]
+    fn frequency_too_high_for_any_divider() {
+        // divider would be 0, below the minimum SCBR of 1
+        assert_eq!(compute_scbr(100, 1_000), Err(Error::FrequencyTooHigh));
+    }
+
+    #[test]
+    fn frequency_too_low_for_any_divider() {
+        // divider would be 1000, above the maximum SCBR of 255
+        assert_eq!(compute_scbr(1_000_000, 1_000), Err(Error::FrequencyTooLow));
+    }
+
+    #[test]
+    fn rounds_to_the_nearest_divider() {
+        // 105 / 10 = 10.5 truncates to 10; rounding half up picks 11
+        assert_eq!(compute_scbr(105, 10), Ok(11));
+    }
+
+    #[test]
+    fn exact_division_picks_that_divider() {
+        assert_eq!(compute_scbr(120_000_000, 1_000_000), Ok(120));
+    }
+}